@@ -6,12 +6,137 @@ use std::path::Path;
 
 use crate::config::LibraryData;
 use crate::file::File;
+use crate::stmt::Features;
 
 /// Some SDK files have '+' in the file name, so we change those to `_`.
 pub(crate) fn clean_file_name(name: &str) -> String {
     name.replace('+', "_")
 }
 
+/// The prefix used for the generated non-inline wrapper symbols.
+///
+/// Kept stable so the wrapper symbol is deterministic across regenerations.
+pub(crate) const WRAPPER_PREFIX: &str = "objc2_wrap_";
+
+/// A generated C trampoline for a `static inline` (or `NS_INLINE`) function.
+///
+/// Apple headers are full of `static inline` helpers that have no exported
+/// symbol and therefore cannot be linked to directly. For each such function
+/// we emit a non-inline wrapper of the form
+/// `ReturnTy objc2_wrap_<name>(args) { return <name>(args); }` into a generated
+/// C/Objective-C source file, along with a matching `extern "C"` Rust
+/// declaration that [`Library::fmt`] renders.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CTrampoline {
+    /// Deterministic, mangled wrapper symbol, e.g. `objc2_wrap_CGRectMake`.
+    pub wrapper_name: String,
+    /// The C wrapper definition, signature and forwarding call included.
+    ///
+    /// Already wrapped in the `#if`/`#endif` availability guard (if any) that
+    /// the original function sits behind, so it is never compiled on a target
+    /// where the forwarded-to inline is `#if`-excluded from the headers.
+    pub c_source: String,
+    /// The `extern "C"` Rust declaration rendered into the framework module.
+    pub rust_decl: String,
+    /// The same features the original declaration is gated behind. These gate
+    /// only the `extern "C"` Rust declaration; the C wrapper is compiled
+    /// regardless of which features are enabled.
+    pub features: Features,
+}
+
+/// A `static inline` / `NS_INLINE` function detected during parsing.
+///
+/// [`Stmt::inline_fn`] returns one of these for each inline function it
+/// encounters, carrying everything needed to emit a wrapper: the translated C
+/// types (`c_ret`/`c_args`) used for the forwarding definition, the
+/// corresponding Rust types (`rust_ret`/`rust_args`) used for the `extern`
+/// declaration, whether the function is variadic, the features it is gated
+/// behind, and the `#if ...` availability line it sits behind in the headers
+/// (if any).
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineFn {
+    pub name: String,
+    pub c_ret: String,
+    pub c_args: Vec<(String, String)>,
+    pub rust_ret: String,
+    pub rust_args: Vec<(String, String)>,
+    pub is_variadic: bool,
+    pub features: Features,
+    pub c_gate: Option<String>,
+}
+
+impl CTrampoline {
+    /// Build a trampoline for a detected inline function, or `None` if the
+    /// function cannot be forwarded portably.
+    ///
+    /// Variadic inlines are skipped: there is no portable way to forward `...`
+    /// without a `va_list` variant, so we drop them rather than emit a wrapper
+    /// that only forwards the fixed arguments.
+    pub fn from_inline_fn(inline: InlineFn) -> Option<Self> {
+        let InlineFn {
+            name,
+            c_ret,
+            c_args,
+            rust_ret,
+            rust_args,
+            is_variadic,
+            features,
+            c_gate,
+        } = inline;
+
+        if is_variadic {
+            return None;
+        }
+
+        // Stable, deterministic symbol so regenerating the bindings doesn't
+        // churn the wrapper names across runs.
+        let wrapper_name = format!("{WRAPPER_PREFIX}{name}");
+
+        let params = c_args
+            .iter()
+            .map(|(ty, arg)| format!("{ty} {arg}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let forwarded = c_args
+            .iter()
+            .map(|(_, arg)| arg.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ret = if c_ret.trim() == "void" { "" } else { "return " };
+
+        let mut c_source = String::new();
+        if let Some(gate) = &c_gate {
+            c_source.push_str(gate);
+            c_source.push('\n');
+        }
+        c_source.push_str(&format!(
+            "{c_ret} {wrapper_name}({params}) {{ {ret}{name}({forwarded}); }}\n"
+        ));
+        if c_gate.is_some() {
+            c_source.push_str("#endif\n");
+        }
+
+        let rust_params = rust_args
+            .iter()
+            .map(|(ty, arg)| format!("{arg}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let rust_ret = if rust_ret.trim() == "()" {
+            String::new()
+        } else {
+            format!(" -> {rust_ret}")
+        };
+        let rust_decl = format!("pub fn {wrapper_name}({rust_params}){rust_ret};");
+
+        Some(Self {
+            wrapper_name,
+            c_source,
+            rust_decl,
+            features,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Default)]
 pub struct Library {
     pub files: BTreeMap<String, File>,
@@ -28,6 +153,23 @@ impl Library {
         }
     }
 
+    /// The trampolines for every `static inline` / `NS_INLINE` function across
+    /// the framework's parsed statements.
+    ///
+    /// This is the `File`/`Library` "emit a wrapper" mode: we walk the parsed
+    /// statements, ask each whether it is an inline function (see
+    /// [`Stmt::inline_fn`]), and turn the ones that can be forwarded portably
+    /// into a non-inline C wrapper plus a matching `extern "C"` Rust
+    /// declaration. Variadic inlines are dropped by [`CTrampoline::from_inline_fn`].
+    fn inline_trampolines(&self) -> Vec<CTrampoline> {
+        self.files
+            .values()
+            .flat_map(|file| &file.stmts)
+            .filter_map(|stmt| stmt.inline_fn())
+            .filter_map(CTrampoline::from_inline_fn)
+            .collect()
+    }
+
     pub fn output(&self, path: &Path) -> io::Result<()> {
         for (name, file) in &self.files {
             let name = clean_file_name(name);
@@ -39,8 +181,42 @@ impl Library {
         // truncate if the file exists
         fs::write(path.join("mod.rs"), self.to_string())?;
 
+        // Emit the generated C trampolines for `static inline` functions, so
+        // that `build.rs` can compile them through `cc` like the other
+        // `extern/*.c` and `extern/*.m` sources.
+        let trampolines = self.inline_trampolines();
+        if !trampolines.is_empty() {
+            let mut path = path.join(format!("{}_inlined", self.link_name));
+            path.set_extension("m");
+            fs::write(&path, self.c_trampolines_source(&trampolines))?;
+        }
+
         Ok(())
     }
+
+    /// Render the generated C/Objective-C source file containing the non-inline
+    /// wrappers of every `static inline` function encountered while parsing.
+    fn c_trampolines_source(&self, trampolines: &[CTrampoline]) -> String {
+        let mut s = String::new();
+        s.push_str("// This file has been automatically generated by `objc2`'s `header-translator`.\n");
+        s.push_str("// DO NOT EDIT\n\n");
+        // The umbrella header pulls in the `static inline` definitions we
+        // forward to below.
+        s.push_str(&format!("#import <{0}/{0}.h>\n\n", self.link_name));
+
+        // Each wrapper carries its own `#if`/`#endif` availability guard (see
+        // `CTrampoline::from_inline_fn`), so a wrapper for an inline that is
+        // `#if`-excluded on this target compiles to nothing rather than failing
+        // against the headers. Cargo-feature gating has no C equivalent, so it
+        // is applied only to the `extern "C"` declaration rendered by `fmt`;
+        // the linker never references an unused wrapper, so compiling it is
+        // harmless.
+        for trampoline in trampolines {
+            s.push_str(&trampoline.c_source);
+        }
+
+        s
+    }
 }
 
 impl fmt::Display for Library {
@@ -99,6 +275,17 @@ impl fmt::Display for Library {
         writeln!(f, "extern \"C\" {{}}")?;
         writeln!(f)?;
 
+        // Declarations for the non-inline wrappers of `static inline`
+        // functions. The matching definitions live in the generated
+        // `<framework>_inlined.m`, compiled by `build.rs` through `cc`.
+        for trampoline in self.inline_trampolines() {
+            write!(f, "{}", trampoline.features.cfg_gate_ln())?;
+            writeln!(f, "extern \"C\" {{")?;
+            writeln!(f, "    {}", trampoline.rust_decl)?;
+            writeln!(f, "}}")?;
+        }
+        writeln!(f)?;
+
         for name in self.files.keys() {
             let name = clean_file_name(name);
             writeln!(f, "#[path = \"{name}.rs\"]")?;
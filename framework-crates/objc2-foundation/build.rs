@@ -0,0 +1,51 @@
+use std::env;
+use std::path::Path;
+
+fn main() {
+    // Compile the trampolines the `header-translator` generated for this
+    // framework's `static inline` / `NS_INLINE` functions through `cc`, the
+    // same way `tests/build.rs` compiles `extern/block_utils.c` and
+    // `extern/encode_utils.m`.
+    //
+    // The generator writes `<link_name>_inlined.m` into the framework's
+    // generated-sources directory (`Library::output`'s `path`, which for the
+    // framework crates is `src/generated`). We scan that directory rather than
+    // hard-coding a file name so the wiring keeps working regardless of the
+    // framework's link name.
+    let generated = Path::new("src/generated");
+    println!("cargo:rerun-if-changed={}", generated.display());
+
+    let mut builder = cc::Build::new();
+    builder.compiler("clang");
+
+    let mut found = false;
+    if let Ok(entries) = generated.read_dir() {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.ends_with("_inlined.m"))
+            {
+                println!("cargo:rerun-if-changed={}", path.display());
+                builder.file(&path);
+                found = true;
+            }
+        }
+    }
+
+    // Nothing to compile when the framework has no inline helpers.
+    if !found {
+        return;
+    }
+
+    // `objc-sys` exports the flags needed to compile Objective-C; fall back to
+    // no extra flags if it didn't (e.g. when building docs).
+    if let Ok(args) = env::var("DEP_OBJC_CC_ARGS") {
+        for flag in args.split(' ').filter(|flag| !flag.is_empty()) {
+            builder.flag(flag);
+        }
+    }
+
+    builder.compile("libobjc2_inlined.a");
+}